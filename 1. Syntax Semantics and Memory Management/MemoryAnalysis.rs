@@ -1,8 +1,10 @@
 // Rust Memory Management Analysis
 // Demonstrates ownership, borrowing, and RAII principles
 
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+use std::thread;
 
 // Example 1: Stack allocation and ownership
 fn ownership_example() {
@@ -17,6 +19,20 @@ fn ownership_example() {
     // vec goes out of scope and is automatically dropped (RAII)
 }
 
+// Example 1b: Ownership transfer via move, contrasted with Copy types
+fn move_semantics_example() {
+    println!("=== Move Semantics Example ===");
+    let s1 = String::from("hello");
+    let s2 = s1;  // s1 is moved into s2; s1 is no longer valid
+    println!("s2: {}, size_of::<String>: {}", s2, std::mem::size_of::<String>());
+    // println!("s1: {}", s1);  // Compile error: value borrowed here after move
+
+    // Copy types duplicate their bits instead of moving, so the original stays usable
+    let x1 = 5;
+    let x2 = x1;
+    println!("x1: {}, x2: {}, size_of::<i32>: {}", x1, x2, std::mem::size_of::<i32>());
+}
+
 // Example 2: Memory safety through borrowing rules
 fn borrowing_example() {
     println!("=== Borrowing Example ===");
@@ -35,6 +51,48 @@ fn borrowing_example() {
     println!("Modified data: {:?}", data);
 }
 
+// Example 2c: What the borrow checker prevents - mutation while a shared
+// reference to the same data is still live (the classic iterator/pointer
+// invalidation hazard)
+fn iterator_invalidation_example() {
+    println!("=== Iterator Invalidation Example ===");
+    let mut v = vec![1, 2, 3];
+
+    let first = &v[0];
+    println!("First element: {}", first);
+    // v.push(4);  // Compile error: cannot borrow `v` as mutable because it
+    //             // is also borrowed as immutable (`first` is still live here).
+    //             // A push may reallocate the backing buffer, which would
+    //             // leave `first` pointing at freed memory - exactly the
+    //             // dangling-pointer bug this rule exists to prevent.
+    println!("First element (still valid): {}", first);
+
+    // Safe version: the immutable borrow's last use is above, so it is no
+    // longer live by the time we mutate.
+    v.push(4);
+    println!("Modified vector: {:?}", v);
+}
+
+// Borrows a slice and derives data from it without taking ownership
+fn vec_min(v: &[i32]) -> Option<i32> {
+    v.iter().fold(None, |min, &x| match min {
+        None => Some(x),
+        Some(m) => Some(if x < m { x } else { m }),
+    })
+}
+
+// Example 2b: A shared reference lets the same owner be reused repeatedly
+fn shared_ref_demo() {
+    println!("=== Shared Reference Demo ===");
+    let v = vec![5, 4, 3, 2, 1];
+
+    let first = &v[0];
+    println!("First element: {}", first);
+
+    println!("Min (first call): {:?}", vec_min(&v));
+    println!("Min (second call): {:?}", vec_min(&v));
+}
+
 // Example 3: Reference counting for shared ownership
 fn shared_ownership_example() {
     println!("=== Shared Ownership Example ===");
@@ -51,6 +109,74 @@ fn shared_ownership_example() {
     println!("Reference count: {}", Rc::strong_count(&shared_data));
 }
 
+// Example 3b: Thread-safe shared ownership with Arc and Mutex
+// Rc is rejected across threads at compile time; Arc/Mutex is accepted.
+fn concurrent_ownership_example() {
+    println!("=== Concurrent Ownership Example ===");
+    let shared_data = Arc::new(Mutex::new(Vec::new()));
+
+    let handles: Vec<_> = (0..5)
+        .map(|i| {
+            let shared_data = Arc::clone(&shared_data);
+            thread::spawn(move || {
+                shared_data.lock().unwrap().push(i);
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("Final vector: {:?}", shared_data.lock().unwrap());
+    println!("Reference count: {}", Arc::strong_count(&shared_data));
+}
+
+// Example 3c: Where Rc leaks - reference cycles, and how Weak avoids them
+struct Node {
+    #[allow(dead_code)]
+    value: i32,
+    parent: RefCell<Weak<RefCell<Node>>>,
+    children: RefCell<Vec<Rc<RefCell<Node>>>>,
+}
+
+fn cycle_and_weak_example() {
+    println!("=== Cycle and Weak Example ===");
+    let parent = Rc::new(RefCell::new(Node {
+        value: 0,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(Vec::new()),
+    }));
+    let child = Rc::new(RefCell::new(Node {
+        value: 1,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(Vec::new()),
+    }));
+
+    // The child holds a Weak back-reference instead of an Rc, so there is no cycle.
+    // If child.parent were an Rc<RefCell<Node>> pointing at parent while
+    // parent.children held an Rc<RefCell<Node>> pointing at child, each would
+    // keep the other's strong count above zero forever - a memory leak that
+    // neither node's Drop impl would ever run to fix.
+    *child.borrow().parent.borrow_mut() = Rc::downgrade(&parent);
+    parent.borrow().children.borrow_mut().push(Rc::clone(&child));
+
+    println!(
+        "Before drop - parent strong: {}, weak: {}",
+        Rc::strong_count(&parent),
+        Rc::weak_count(&parent)
+    );
+
+    drop(child);
+    parent.borrow().children.borrow_mut().clear();
+
+    println!(
+        "After drop - parent strong: {}, weak: {}",
+        Rc::strong_count(&parent),
+        Rc::weak_count(&parent)
+    );
+}
+
 // Example 4: Zero-cost abstractions
 fn zero_cost_abstractions() {
     println!("=== Zero-Cost Abstractions ===");
@@ -71,8 +197,13 @@ fn main() {
     println!("====================================");
     
     ownership_example();
+    move_semantics_example();
     borrowing_example();
+    iterator_invalidation_example();
+    shared_ref_demo();
     shared_ownership_example();
+    concurrent_ownership_example();
+    cycle_and_weak_example();
     zero_cost_abstractions();
     
     println!("\nKey Features:");
@@ -80,4 +211,19 @@ fn main() {
     println!("- Zero-cost abstractions");
     println!("- No garbage collector");
     println!("- Ownership system prevents data races");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_min_returns_smallest_element() {
+        assert_eq!(vec_min(&[5, 4, 3, 2, 1]), Some(1));
+    }
+
+    #[test]
+    fn vec_min_of_empty_slice_is_none() {
+        assert_eq!(vec_min(&[]), None);
+    }
 }
\ No newline at end of file